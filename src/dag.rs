@@ -59,55 +59,79 @@ where
         Ok(self)
     }
 
+    fn elements<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        self.ids.keys()
+    }
+
     fn lt(&self, lo: &T, hi: &T) -> Result<bool, Self::Error> {
         match (self.ids.get(lo), self.ids.get(hi)) {
-            (Some(lo_idx), Some(hi_idx)) => {
-                let mut dfs = Dfs::new(&self.dag, *lo_idx);
-                while let Some(n) = dfs.next(&self.dag) {
-                    if n == *hi_idx {
-                        return Ok(true);
-                    }
-                }
-                Ok(false)
-            }
+            (Some(lo_idx), Some(hi_idx)) => Ok(self.reachable(*lo_idx, *hi_idx)),
             _ => Ok(false),
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
 
-    use quickcheck::{quickcheck, Arbitrary, Gen};
+    fn topological_order(&self) -> Result<Vec<&T>, Self::Error> {
+        let order = petgraph::algo::toposort(self.dag.graph(), None)
+            .expect("DagPartOrd invariant violated: graph should be acyclic");
+        Ok(order.into_iter().map(|ix| &self.dag[ix]).collect())
+    }
 
-    impl Arbitrary for DagPartOrd<u8> {
-        fn arbitrary(g: &mut Gen) -> Self {
-            let mut ppo = DagPartOrd::empty();
-            let pairs = Vec::<(u8, u8)>::arbitrary(g);
-            for (x, y) in pairs {
-                if x <= y {
-                    ppo = ppo.add(x, y).unwrap();
-                } else {
-                    ppo = ppo.add(y, x).unwrap();
-                }
+    fn covers<'a>(&'a self) -> impl Iterator<Item = (&'a T, &'a T)>
+    where
+        T: 'a,
+    {
+        let indices: Vec<NodeIndex> = self.dag.graph().node_indices().collect();
+        let mut seen: Vec<(NodeIndex, NodeIndex)> = Vec::new();
+        let mut result = Vec::new();
+        for edge in self.dag.graph().edge_indices() {
+            let (a, b) = self
+                .dag
+                .graph()
+                .edge_endpoints(edge)
+                .expect("edge index from this graph");
+            // `add` doesn't reject re-adding the same edge, so the graph may
+            // contain exact duplicates; dedupe before returning.
+            if seen.contains(&(a, b)) {
+                continue;
+            }
+            let is_cover = !indices
+                .iter()
+                .any(|&c| c != a && c != b && self.reachable(a, c) && self.reachable(c, b));
+            if is_cover {
+                seen.push((a, b));
+                result.push((&self.dag[a], &self.dag[b]));
             }
-            ppo
         }
+        result.into_iter()
+    }
+}
 
-        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-            let mut iters = Vec::new();
-            match self.dag.graph().node_indices().next() {
-                Some(ix) => {
-                    let mut new = self.clone();
-                    new.dag.remove_node(ix);
-                    iters.push(new);
-                }
-                None => (),
+impl<T> DagPartOrd<T>
+where
+    T: Clone,
+    T: Eq,
+    T: Hash,
+{
+    fn reachable(&self, from: NodeIndex, to: NodeIndex) -> bool {
+        let mut dfs = Dfs::new(&self.dag, from);
+        while let Some(n) = dfs.next(&self.dag) {
+            if n == to {
+                return true;
             }
-            Box::new(iters.into_iter())
         }
+        false
     }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::r#trait::PartialOrdering;
+    use quickcheck::quickcheck;
 
     #[test]
     fn empty() {
@@ -128,6 +152,94 @@ mod tests {
         assert!(ppo.le(&"x".to_string(), &"z".to_string()).unwrap());
     }
 
+    #[test]
+    fn pcmp() {
+        let mut ppo = DagPartOrd::empty();
+        ppo = ppo.add(1u8, 2u8).unwrap();
+        assert_eq!(ppo.pcmp(&1, &2).unwrap(), PartialOrdering::Less);
+        assert_eq!(ppo.pcmp(&2, &1).unwrap(), PartialOrdering::Greater);
+        assert_eq!(ppo.pcmp(&1, &1).unwrap(), PartialOrdering::Equal);
+        assert_eq!(ppo.pcmp(&1, &3).unwrap(), PartialOrdering::Incomparable);
+    }
+
+    #[test]
+    fn diamond_lattice() {
+        // 0 < 1, 0 < 2, 1 < 3, 2 < 3
+        let mut ppo = DagPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(0u8, 2u8).unwrap();
+        ppo = ppo.add(1u8, 3u8).unwrap();
+        ppo = ppo.add(2u8, 3u8).unwrap();
+        assert_eq!(ppo.meet(&1, &2).unwrap(), Some(0));
+        assert_eq!(ppo.join(&1, &2).unwrap(), Some(3));
+        assert!(ppo.is_lattice().unwrap());
+    }
+
+    #[test]
+    fn minimal_maximal_elements() {
+        // 0 < 1, 0 < 2, 1 < 3, 2 < 3
+        let mut ppo = DagPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(0u8, 2u8).unwrap();
+        ppo = ppo.add(1u8, 3u8).unwrap();
+        ppo = ppo.add(2u8, 3u8).unwrap();
+        assert_eq!(ppo.minimal_elements().unwrap(), vec![&0]);
+        assert_eq!(ppo.maximal_elements().unwrap(), vec![&3]);
+    }
+
+    #[test]
+    fn topological_order_respects_lt() {
+        // 0 < 1 < 2, and a disconnected 4 < 5.
+        let mut ppo = DagPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(1u8, 2u8).unwrap();
+        ppo = ppo.add(4u8, 5u8).unwrap();
+        let order = ppo.topological_order().unwrap();
+        let pos = |x: u8| order.iter().position(|&&e| e == x).unwrap();
+        assert!(pos(0) < pos(1));
+        assert!(pos(1) < pos(2));
+        assert!(pos(4) < pos(5));
+        assert_eq!(order.len(), 5);
+    }
+
+    #[test]
+    fn covers_omits_transitive_edge() {
+        let mut ppo = DagPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(1u8, 2u8).unwrap();
+        ppo = ppo.add(0u8, 2u8).unwrap(); // redundant, not a cover
+        let mut covers: Vec<(u8, u8)> = ppo.covers().map(|(&a, &b)| (a, b)).collect();
+        covers.sort();
+        assert_eq!(covers, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn covers_dedupes_repeated_pair() {
+        let mut ppo = DagPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(0u8, 1u8).unwrap(); // re-adding the same pair is legal
+        let covers: Vec<(u8, u8)> = ppo.covers().map(|(&a, &b)| (a, b)).collect();
+        assert_eq!(covers, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn empty_traversals() {
+        let ppo = DagPartOrd::<u8>::empty();
+        assert!(ppo.minimal_elements().unwrap().is_empty());
+        assert!(ppo.maximal_elements().unwrap().is_empty());
+        assert!(ppo.topological_order().unwrap().is_empty());
+        assert!(ppo.covers().next().is_none());
+    }
+
+    #[test]
+    fn no_meet_without_lower_bound() {
+        let mut ppo = DagPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(2u8, 3u8).unwrap();
+        assert_eq!(ppo.meet(&1, &3).unwrap(), None);
+        assert!(!ppo.is_lattice().unwrap());
+    }
+
     quickcheck! {
         fn antisymmetric_two(x: u8, y: u8) -> bool {
             if x == y {
@@ -147,34 +259,26 @@ mod tests {
             ppo = ppo.add(y, z).unwrap();
             ppo.le(&x, &z).unwrap()
         }
+    }
 
-        fn add_le(ppo: DagPartOrd<u8>, x: u8, y: u8) -> bool {
-            match ppo.add(x, y) {
-                Err(_) => true,
-                Ok(ppo) => {
-                    ppo.le(&x, &y).unwrap() && (!ppo.le(&y, &x).unwrap() || x == y)
-                }
-            }
-        }
-
-
-        fn reflexive(ppo: DagPartOrd<u8>, x: u8) -> bool {
-            ppo.le(&x, &x).unwrap()
-        }
+    // The reflexivity/antisymmetry/transitivity/le-lt/add-le laws themselves
+    // are checked once, generically, via `check_laws`/`any_fin_part_ord`
+    // rather than re-implemented per backend; see the `pairs` module's
+    // `laws` test for the equivalent coverage over `PairPartOrd`.
+    #[cfg(feature = "testing")]
+    mod laws {
+        use proptest::prelude::*;
 
-        fn antisymmetric(ppo: DagPartOrd<u8>, x: u8, y: u8) -> bool {
-            if ppo.le(&x, &y).unwrap() && ppo.le(&y, &x).unwrap() {
-                x == y
-            } else {
-                true
-            }
-        }
+        use super::DagPartOrd;
+        use crate::testing::{any_fin_part_ord, check_laws};
 
-        fn transitive(ppo: DagPartOrd<u8>, x: u8, y: u8, z: u8) -> bool {
-            if ppo.le(&x, &y).unwrap() && ppo.le(&y, &z).unwrap() {
-                ppo.le(&x, &z).unwrap()
-            } else {
-                true
+        proptest! {
+            #[test]
+            fn laws_hold(
+                order in any_fin_part_ord::<DagPartOrd<u8>, u8>(),
+                samples in prop::collection::vec(any::<u8>(), 0..8),
+            ) {
+                prop_assert!(check_laws(&order, &samples).is_empty());
             }
         }
     }
@@ -12,3 +12,6 @@ pub use pairs::*;
 
 mod r#trait;
 pub use r#trait::*;
+
+#[cfg(feature = "testing")]
+pub mod testing;
@@ -56,22 +56,92 @@ where
         Ok(self)
     }
 
-    fn lt(&self, lo: &T, hi: &T) -> Result<bool, Self::Error> {
+    fn elements<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a,
+    {
+        let mut elems: Vec<&T> = Vec::new();
         for p in &self.pairs {
-            if &p.lo == lo {
-                if &p.hi == hi {
-                    return Ok(true);
-                }
-                // DFS
-                if let Ok(b) = self.lt(&p.hi, hi) {
-                    if b {
-                        return Ok(true);
-                    }
+            if !elems.contains(&&p.lo) {
+                elems.push(&p.lo);
+            }
+            if !elems.contains(&&p.hi) {
+                elems.push(&p.hi);
+            }
+        }
+        elems.into_iter()
+    }
+
+    fn lt(&self, lo: &T, hi: &T) -> Result<bool, Self::Error> {
+        // Iterative worklist DFS over the transitive closure of `pairs`,
+        // rather than recursing into `lt` itself: the pair list can contain
+        // long chains, and a recursive walk would overflow the stack.
+        let mut frontier: Vec<&T> = self
+            .pairs
+            .iter()
+            .filter(|p| &p.lo == lo)
+            .map(|p| &p.hi)
+            .collect();
+        let mut visited: Vec<&T> = Vec::new();
+        while let Some(node) = frontier.pop() {
+            if node == hi {
+                return Ok(true);
+            }
+            if visited.contains(&node) {
+                continue;
+            }
+            visited.push(node);
+            for p in &self.pairs {
+                if &p.lo == node {
+                    frontier.push(&p.hi);
                 }
             }
         }
         Ok(false)
     }
+
+    fn topological_order(&self) -> Result<Vec<&T>, Self::Error> {
+        // Kahn's algorithm: repeatedly emit an element with no remaining
+        // element strictly below it.
+        let mut remaining: Vec<&T> = self.elements().collect();
+        let mut order = Vec::with_capacity(remaining.len());
+        while !remaining.is_empty() {
+            let idx = remaining.iter().position(|e| {
+                !remaining
+                    .iter()
+                    .any(|x| *x != *e && self.lt(x, e).unwrap_or(false))
+            });
+            match idx {
+                Some(i) => order.push(remaining.remove(i)),
+                None => panic!(
+                    "PairPartOrd invariant violated: no element with in-degree zero remains"
+                ),
+            }
+        }
+        Ok(order)
+    }
+
+    fn covers<'a>(&'a self) -> impl Iterator<Item = (&'a T, &'a T)>
+    where
+        T: 'a,
+    {
+        let elems: Vec<&T> = self.elements().collect();
+        let mut result: Vec<(&T, &T)> = Vec::new();
+        for p in &self.pairs {
+            let is_cover = !elems.iter().any(|&c| {
+                *c != p.lo
+                    && *c != p.hi
+                    && self.lt(&p.lo, c).unwrap_or(false)
+                    && self.lt(c, &p.hi).unwrap_or(false)
+            });
+            // `pairs` may contain exact duplicates (`add` doesn't reject
+            // re-adding the same pair), so dedupe before returning.
+            if is_cover && !result.iter().any(|&(a, b)| *a == p.lo && *b == p.hi) {
+                result.push((&p.lo, &p.hi));
+            }
+        }
+        result.into_iter()
+    }
 }
 
 impl<T: Eq> PairPartOrd<T>
@@ -112,32 +182,8 @@ where
 mod tests {
     use super::*;
 
-    use quickcheck::{quickcheck, Arbitrary, Gen};
-
-    impl Arbitrary for PairPartOrd<u8> {
-        fn arbitrary(g: &mut Gen) -> Self {
-            let mut ppo = PairPartOrd::empty();
-            let pairs = Vec::<(u8, u8)>::arbitrary(g);
-            for (x, y) in pairs {
-                if x <= y {
-                    ppo = ppo.add(x, y).unwrap();
-                } else {
-                    ppo = ppo.add(y, x).unwrap();
-                }
-            }
-            ppo
-        }
-
-        fn shrink(&self) -> Box<dyn Iterator<Item = Self>> {
-            let mut iters = Vec::new();
-            for i in 0..self.pairs.len() {
-                let mut pairs = self.pairs.clone();
-                pairs.remove(i);
-                iters.push(PairPartOrd { pairs });
-            }
-            Box::new(iters.into_iter())
-        }
-    }
+    use crate::r#trait::PartialOrdering;
+    use quickcheck::quickcheck;
 
     #[test]
     fn empty_valid() {
@@ -165,6 +211,94 @@ mod tests {
         assert!(ppo.le(&"x".to_string(), &"z".to_string()).unwrap());
     }
 
+    #[test]
+    fn pcmp() {
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(1u8, 2u8).unwrap();
+        assert_eq!(ppo.pcmp(&1, &2).unwrap(), PartialOrdering::Less);
+        assert_eq!(ppo.pcmp(&2, &1).unwrap(), PartialOrdering::Greater);
+        assert_eq!(ppo.pcmp(&1, &1).unwrap(), PartialOrdering::Equal);
+        assert_eq!(ppo.pcmp(&1, &3).unwrap(), PartialOrdering::Incomparable);
+    }
+
+    #[test]
+    fn diamond_lattice() {
+        // 0 < 1, 0 < 2, 1 < 3, 2 < 3
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(0u8, 2u8).unwrap();
+        ppo = ppo.add(1u8, 3u8).unwrap();
+        ppo = ppo.add(2u8, 3u8).unwrap();
+        assert_eq!(ppo.meet(&1, &2).unwrap(), Some(0));
+        assert_eq!(ppo.join(&1, &2).unwrap(), Some(3));
+        assert!(ppo.is_lattice().unwrap());
+    }
+
+    #[test]
+    fn no_meet_without_lower_bound() {
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(2u8, 3u8).unwrap();
+        assert_eq!(ppo.meet(&1, &3).unwrap(), None);
+        assert!(!ppo.is_lattice().unwrap());
+    }
+
+    #[test]
+    fn minimal_maximal_elements() {
+        // 0 < 1, 0 < 2, 1 < 3, 2 < 3
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(0u8, 2u8).unwrap();
+        ppo = ppo.add(1u8, 3u8).unwrap();
+        ppo = ppo.add(2u8, 3u8).unwrap();
+        assert_eq!(ppo.minimal_elements().unwrap(), vec![&0]);
+        assert_eq!(ppo.maximal_elements().unwrap(), vec![&3]);
+    }
+
+    #[test]
+    fn topological_order_respects_lt() {
+        // 0 < 1 < 2, and a disconnected 4 < 5.
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(1u8, 2u8).unwrap();
+        ppo = ppo.add(4u8, 5u8).unwrap();
+        let order = ppo.topological_order().unwrap();
+        let pos = |x: u8| order.iter().position(|&&e| e == x).unwrap();
+        assert!(pos(0) < pos(1));
+        assert!(pos(1) < pos(2));
+        assert!(pos(4) < pos(5));
+        assert_eq!(order.len(), 5);
+    }
+
+    #[test]
+    fn covers_omits_transitive_edge() {
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(1u8, 2u8).unwrap();
+        ppo = ppo.add(0u8, 2u8).unwrap(); // redundant, not a cover
+        let mut covers: Vec<(u8, u8)> = ppo.covers().map(|(&a, &b)| (a, b)).collect();
+        covers.sort();
+        assert_eq!(covers, vec![(0, 1), (1, 2)]);
+    }
+
+    #[test]
+    fn covers_dedupes_repeated_pair() {
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(0u8, 1u8).unwrap(); // re-adding the same pair is legal
+        let covers: Vec<(u8, u8)> = ppo.covers().map(|(&a, &b)| (a, b)).collect();
+        assert_eq!(covers, vec![(0, 1)]);
+    }
+
+    #[test]
+    fn empty_traversals() {
+        let ppo = PairPartOrd::<u8>::empty();
+        assert!(ppo.minimal_elements().unwrap().is_empty());
+        assert!(ppo.maximal_elements().unwrap().is_empty());
+        assert!(ppo.topological_order().unwrap().is_empty());
+        assert!(ppo.covers().next().is_none());
+    }
+
     quickcheck! {
         fn antisymmetric_two(x: u8, y: u8) -> bool {
             if x == y {
@@ -186,35 +320,26 @@ mod tests {
             assert!(ppo.valid());
             ppo.le(&x, &z).unwrap()
         }
+    }
 
-        // TODO: Stack overflow :-(
-        // fn add_le(ppo: PairPartOrd<u8>, x: u8, y: u8) -> bool {
-        //     match ppo.add(x, y) {
-        //         Err(_) => true,
-        //         Ok(ppo) => {
-        //             ppo.le(&x, &y).unwrap() && (!ppo.le(&y, &x).unwrap() || x == y)
-        //         }
-        //     }
-        // }
-
-
-        fn reflexive(ppo: PairPartOrd<u8>, x: u8) -> bool {
-            ppo.le(&x, &x).unwrap()
-        }
+    // The reflexivity/antisymmetry/transitivity/le-lt/add-le laws themselves
+    // are checked once, generically, via `check_laws`/`any_fin_part_ord`
+    // rather than re-implemented per backend; see the `dag` module's
+    // `laws` test for the equivalent coverage over `DagPartOrd`.
+    #[cfg(feature = "testing")]
+    mod laws {
+        use proptest::prelude::*;
 
-        fn antisymmetric(ppo: PairPartOrd<u8>, x: u8, y: u8) -> bool {
-            if ppo.le(&x, &y).unwrap() && ppo.le(&y, &x).unwrap() {
-                x == y
-            } else {
-                true
-            }
-        }
+        use super::PairPartOrd;
+        use crate::testing::{any_fin_part_ord, check_laws};
 
-        fn transitive(ppo: PairPartOrd<u8>, x: u8, y: u8, z: u8) -> bool {
-            if ppo.le(&x, &y).unwrap() && ppo.le(&y, &z).unwrap() {
-                ppo.le(&x, &z).unwrap()
-            } else {
-                true
+        proptest! {
+            #[test]
+            fn laws_hold(
+                order in any_fin_part_ord::<PairPartOrd<u8>, u8>(),
+                samples in prop::collection::vec(any::<u8>(), 0..8),
+            ) {
+                prop_assert!(check_laws(&order, &samples).is_empty());
             }
         }
     }
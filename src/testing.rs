@@ -0,0 +1,128 @@
+//! Reusable law-checking and property-testing helpers for [`FinPartOrd`]
+//! implementations.
+//!
+//! [`check_laws`] packages the reflexivity/antisymmetry/transitivity and
+//! related law checks as one reusable function, and [`any_fin_part_ord`]
+//! packages the corresponding generator. The backends in this crate test their
+//! laws through these two helpers, and downstream crates implementing
+//! [`FinPartOrd`] can test their own backends the same way.
+
+use proptest::prelude::*;
+
+use crate::r#trait::FinPartOrd;
+
+/// A single witness tuple demonstrating that an order law doesn't hold.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum LawViolation<T> {
+    /// `order.le(x, x)` was not `true`.
+    Reflexivity { x: T },
+    /// `order.le(x, y) && order.le(y, x)` held for `x != y`.
+    Antisymmetry { x: T, y: T },
+    /// `order.le(x, y) && order.le(y, z)` held but `order.le(x, z)` did not.
+    Transitivity { x: T, y: T, z: T },
+    /// `order.le(x, y)` did not agree with `x == y || order.lt(x, y)`.
+    LeLtCompatibility { x: T, y: T },
+    /// `order.add(x, y)` succeeded but the result didn't satisfy `le(x, y)`.
+    AddLe { x: T, y: T },
+}
+
+/// Check the [`FinPartOrd`] laws across all tuples drawn from `samples`,
+/// returning a witness for every violation found rather than panicking.
+///
+/// An empty result means every law held for every sampled tuple.
+pub fn check_laws<P, T>(order: &P, samples: &[T]) -> Vec<LawViolation<T>>
+where
+    P: FinPartOrd<T> + Clone,
+    T: Clone + PartialEq,
+{
+    let mut violations = Vec::new();
+
+    for x in samples {
+        if !order.le(x, x).unwrap_or(false) {
+            violations.push(LawViolation::Reflexivity { x: x.clone() });
+        }
+    }
+
+    for x in samples {
+        for y in samples {
+            let le_xy = order.le(x, y).unwrap_or(false);
+            let le_yx = order.le(y, x).unwrap_or(false);
+            if le_xy && le_yx && x != y {
+                violations.push(LawViolation::Antisymmetry {
+                    x: x.clone(),
+                    y: y.clone(),
+                });
+            }
+
+            let lt_xy = order.lt(x, y).unwrap_or(false);
+            if le_xy != (x == y || lt_xy) {
+                violations.push(LawViolation::LeLtCompatibility {
+                    x: x.clone(),
+                    y: y.clone(),
+                });
+            }
+
+            if let Ok(added) = order.clone().add(x.clone(), y.clone()) {
+                if !added.le(x, y).unwrap_or(false) {
+                    violations.push(LawViolation::AddLe {
+                        x: x.clone(),
+                        y: y.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    for x in samples {
+        for y in samples {
+            for z in samples {
+                if order.le(x, y).unwrap_or(false)
+                    && order.le(y, z).unwrap_or(false)
+                    && !order.le(x, z).unwrap_or(false)
+                {
+                    violations.push(LawViolation::Transitivity {
+                        x: x.clone(),
+                        y: y.clone(),
+                        z: z.clone(),
+                    });
+                }
+            }
+        }
+    }
+
+    violations
+}
+
+/// A [`Strategy`] that generates arbitrary valid [`FinPartOrd`]s by folding
+/// random `(lo, hi)` pairs through [`add`][FinPartOrd::add], discarding any
+/// pair that `add` rejects.
+pub fn any_fin_part_ord<P, T>() -> impl Strategy<Value = P>
+where
+    P: FinPartOrd<T> + Clone + std::fmt::Debug,
+    T: Arbitrary + Clone + PartialEq,
+{
+    proptest::collection::vec(any::<(T, T)>(), 0..32).prop_map(|pairs| {
+        let mut order = P::empty();
+        for (lo, hi) in pairs {
+            order = match order.clone().add(lo, hi) {
+                Ok(next) => next,
+                Err(_) => order,
+            };
+        }
+        order
+    })
+}
+
+#[cfg(all(test, feature = "pairs"))]
+mod tests {
+    use super::*;
+    use crate::PairPartOrd;
+
+    #[test]
+    fn check_laws_on_valid_order() {
+        let mut ppo = PairPartOrd::empty();
+        ppo = ppo.add(0u8, 1u8).unwrap();
+        ppo = ppo.add(1u8, 2u8).unwrap();
+        assert!(check_laws(&ppo, &[0u8, 1, 2, 3]).is_empty());
+    }
+}
@@ -1,3 +1,15 @@
+/// The result of comparing two elements of a [`FinPartOrd`].
+///
+/// Unlike [`std::cmp::Ordering`], a partial order admits pairs of elements
+/// that are related in neither direction, hence [`Incomparable`][Self::Incomparable].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PartialOrdering {
+    Less,
+    Equal,
+    Greater,
+    Incomparable,
+}
+
 /// Trait for finite partial orders.
 ///
 /// Laws:
@@ -18,6 +30,11 @@ where
 
     fn add(self, lo: T, hi: T) -> Result<Self, Self::Error>;
 
+    /// Iterate over the elements of the order.
+    fn elements<'a>(&'a self) -> impl Iterator<Item = &'a T>
+    where
+        T: 'a;
+
     /// Check if one element is less than another.
     ///
     /// May return `true` when `lo == hi`, even if that element hasn't been
@@ -35,4 +52,151 @@ where
         }
         self.lt(lo, hi)
     }
+
+    /// Compare two elements, returning all four possible outcomes of a
+    /// partial order rather than forcing callers to combine two [`lt`][Self::lt]
+    /// calls.
+    fn pcmp(&self, a: &T, b: &T) -> Result<PartialOrdering, Self::Error>
+    where
+        T: PartialEq,
+    {
+        if a == b {
+            return Ok(PartialOrdering::Equal);
+        }
+        if self.lt(a, b)? {
+            return Ok(PartialOrdering::Less);
+        }
+        if self.lt(b, a)? {
+            return Ok(PartialOrdering::Greater);
+        }
+        Ok(PartialOrdering::Incomparable)
+    }
+
+    /// The greatest lower bound of `a` and `b`, or `None` if it doesn't
+    /// exist or isn't unique.
+    fn meet(&self, a: &T, b: &T) -> Result<Option<T>, Self::Error>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut lower_bounds = Vec::new();
+        for x in self.elements() {
+            if self.le(x, a)? && self.le(x, b)? {
+                lower_bounds.push(x);
+            }
+        }
+        for candidate in &lower_bounds {
+            let mut is_greatest = true;
+            for other in &lower_bounds {
+                if !self.le(other, candidate)? {
+                    is_greatest = false;
+                    break;
+                }
+            }
+            if is_greatest {
+                return Ok(Some((*candidate).clone()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// The least upper bound of `a` and `b`, or `None` if it doesn't exist
+    /// or isn't unique.
+    fn join(&self, a: &T, b: &T) -> Result<Option<T>, Self::Error>
+    where
+        T: Clone + PartialEq,
+    {
+        let mut upper_bounds = Vec::new();
+        for x in self.elements() {
+            if self.le(a, x)? && self.le(b, x)? {
+                upper_bounds.push(x);
+            }
+        }
+        for candidate in &upper_bounds {
+            let mut is_least = true;
+            for other in &upper_bounds {
+                if !self.le(candidate, other)? {
+                    is_least = false;
+                    break;
+                }
+            }
+            if is_least {
+                return Ok(Some((*candidate).clone()));
+            }
+        }
+        Ok(None)
+    }
+
+    /// Check whether every pair of elements has both a [`meet`][Self::meet]
+    /// and a [`join`][Self::join].
+    fn is_lattice(&self) -> Result<bool, Self::Error>
+    where
+        T: Clone + PartialEq,
+    {
+        let elems: Vec<&T> = self.elements().collect();
+        for a in &elems {
+            for b in &elems {
+                if self.meet(a, b)?.is_none() || self.join(a, b)?.is_none() {
+                    return Ok(false);
+                }
+            }
+        }
+        Ok(true)
+    }
+
+    /// The elements with no element strictly below them.
+    fn minimal_elements(&self) -> Result<Vec<&T>, Self::Error>
+    where
+        T: PartialEq,
+    {
+        let elems: Vec<&T> = self.elements().collect();
+        let mut result = Vec::new();
+        for e in &elems {
+            let mut is_minimal = true;
+            for x in &elems {
+                if *x != *e && self.lt(x, e)? {
+                    is_minimal = false;
+                    break;
+                }
+            }
+            if is_minimal {
+                result.push(*e);
+            }
+        }
+        Ok(result)
+    }
+
+    /// The elements with no element strictly above them.
+    fn maximal_elements(&self) -> Result<Vec<&T>, Self::Error>
+    where
+        T: PartialEq,
+    {
+        let elems: Vec<&T> = self.elements().collect();
+        let mut result = Vec::new();
+        for e in &elems {
+            let mut is_maximal = true;
+            for x in &elems {
+                if *x != *e && self.lt(e, x)? {
+                    is_maximal = false;
+                    break;
+                }
+            }
+            if is_maximal {
+                result.push(*e);
+            }
+        }
+        Ok(result)
+    }
+
+    /// A linear extension of the partial order, i.e. an ordering of the
+    /// elements consistent with `lt`.
+    ///
+    /// Disconnected elements are included; an empty order yields an empty
+    /// vector.
+    fn topological_order(&self) -> Result<Vec<&T>, Self::Error>;
+
+    /// The covering relation of the partial order, i.e. the Hasse diagram:
+    /// pairs `(a, b)` with `a < b` and no `c` such that `a < c < b`.
+    fn covers<'a>(&'a self) -> impl Iterator<Item = (&'a T, &'a T)>
+    where
+        T: 'a;
 }